@@ -0,0 +1,136 @@
+//! Blocking variants of the Twilio SMS API, built on `reqwest::blocking`.
+//!
+//! This module requires the `blocking` feature (enabled by default). It is kept around for
+//! callers who aren't running inside an async runtime; new code should prefer
+//! [`crate::client::TwilioClient`].
+
+use std::cmp::min;
+use std::thread::sleep;
+
+use crate::{MessageStatus, PollConfig, TWRSError, TwilioReply};
+
+impl TwilioReply {
+    /// Deserialize the response from the Twilio API directly from the `reqwest::blocking::Response`
+    /// struct
+    pub fn decode(response: &mut reqwest::blocking::Response) -> Result<TwilioReply, TWRSError> {
+        let mut buf: Vec<u8> = Vec::new();
+        response
+            .copy_to(&mut buf)
+            .map_err(TWRSError::HTTPRequestError)?;
+        let str_t = String::from_utf8(buf).map_err(TWRSError::Utf8DecodeFailure)?;
+
+        crate::TwilioReply::decode_str(&str_t).map_err(TWRSError::URLDecodeFailure)
+    }
+}
+
+/// Main function of the library which sends the request and returns the response
+/// response. Will error out on a `TWRSError::HTTPRequestError` if the send results in a failure
+pub fn send_message(
+    account_sid: &str,
+    auth_token: &str,
+    body: String,
+) -> Result<reqwest::blocking::Response, TWRSError> {
+    let endpoint = "https://api.twilio.com/2010-04-01/Accounts".to_string();
+    let uri = format!("{}/{}/Messages.json", endpoint, account_sid);
+
+    reqwest::blocking::Client::new()
+        .post(&uri)
+        .header("Content-Type", "application/x-www-form-urlencoded")
+        .basic_auth(account_sid, Some(auth_token))
+        .body(body)
+        .send()
+        .map_err(TWRSError::HTTPRequestError)
+}
+
+/// This will check if the status is set to delivered within the Twilio API
+/// Polls the message's resource URI with exponential backoff (per `config`) while the status
+/// is pending (see `MessageStatus::is_pending`). Returns `TWRSError::NotDelivered` if the
+/// message reaches a terminal failure status, or `TWRSError::Timeout` if `config.max_attempts`
+/// is exhausted while still pending
+pub fn is_delivered(
+    response: &mut reqwest::blocking::Response,
+    account_sid: &str,
+    auth_token: &str,
+    config: PollConfig,
+) -> Result<MessageStatus, TWRSError> {
+    let resp_body = TwilioReply::decode(response)?;
+    let mut resp_status = resp_body.status;
+    let url = format!("https://api.twilio.com/{}", resp_body.uri);
+
+    let mut delay = config.initial_delay;
+
+    for _ in 0..config.max_attempts {
+        if !resp_status.is_pending() {
+            break;
+        }
+
+        sleep(delay);
+        delay = min(delay * 2, config.max_delay);
+
+        let mut sub_r = reqwest::blocking::Client::new()
+            .get(&url)
+            .basic_auth(account_sid, Some(auth_token))
+            .send()
+            .map_err(TWRSError::HTTPRequestError)?;
+        let sub_res = TwilioReply::decode(&mut sub_r)?;
+        resp_status = sub_res.status;
+    }
+
+    match resp_status {
+        MessageStatus::Delivered | MessageStatus::Read => Ok(resp_status),
+        _ if resp_status.is_pending() => Err(TWRSError::Timeout(resp_status)),
+        _ => Err(TWRSError::NotDelivered(resp_status)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    #[ignore]
+    fn test_full() {
+        // Be sure to have the follow environment variables set before running this ignored test
+        // export TW_TO="COUNTRYCODE_PHONENUMBER"
+        // export TW_FROM="COUNTRYCODE_PHONENUMBER"
+        // export TW_SID="ACCOUNT_SID"
+        // export TW_TOKEN="ACCOUNT_TOKEN"
+        use crate::blocking;
+        use crate::{MessageStatus, PollConfig, TwilioSend};
+        use std::env::var;
+
+        use reqwest::StatusCode;
+
+        // Getting your Twilio info to test sending an SMS
+        let tw_to = var("TW_TO").unwrap();
+        let tw_from = var("TW_FROM").unwrap();
+        let tw_sid = var("TW_SID").unwrap();
+        let tw_token = var("TW_TOKEN").unwrap();
+
+        // Create the request body and encode the message for the API
+        let t: TwilioSend = TwilioSend {
+            To: &tw_to,
+            From: &tw_from,
+            Body: Some("Hiya"),
+            MessagingServiceSid: None,
+            SendAt: None,
+            ScheduleType: None,
+            StatusCallback: None,
+            MediaUrl: Vec::new(),
+        };
+        let t_s = t.encode().expect("Error converting to url encoded string");
+
+        // Send the message to the API endpoint
+        let mut response =
+            blocking::send_message(&tw_sid, &tw_token, t_s).expect("Error with HTTP request");
+
+        // Server responds with 201 (Created) on the initial response
+        assert_eq!(StatusCode::from_u16(201).unwrap(), response.status());
+
+        // Run the loop to make sure the message was delivered
+        let delivered =
+            blocking::is_delivered(&mut response, &tw_sid, &tw_token, PollConfig::default())
+                .expect("Error SMS not delivered");
+
+        // Checking the delivered state, and fail on an error
+        assert_eq!(delivered, MessageStatus::Delivered);
+    }
+}
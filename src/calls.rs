@@ -0,0 +1,132 @@
+//! Voice Call resource: placing outbound calls that are driven by a TwiML document (see
+//! [`crate::twiml`]) hosted at a `url` Twilio fetches when the call connects.
+
+use serde::{Deserialize, Serialize};
+use serde_urlencoded as url_encode;
+
+use crate::client::TwilioClient;
+use crate::{TWRSError, ENDPOINT};
+
+/// Custom struct to serialize the HTTP POST data for placing an outbound call
+#[allow(non_snake_case)]
+#[derive(Serialize, Deserialize, Default)]
+pub struct OutboundCall<'s> {
+    pub r#From: &'s str,
+    pub To: &'s str,
+    /// A URL Twilio requests (and executes the returned TwiML from) once the call connects
+    pub Url: &'s str,
+}
+
+impl<'s> OutboundCall<'s> {
+    pub fn new() -> OutboundCall<'s> {
+        OutboundCall::default()
+    }
+
+    /// This function converts from the struct to a string of url encoded formatting
+    pub fn encode(self) -> Result<String, TWRSError> {
+        url_encode::to_string(&self).map_err(TWRSError::URLEncodeFailure)
+    }
+}
+
+/// Struct to deserialize the Twilio reply from placing a call
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq)]
+pub struct CallReply {
+    sid: String,
+    date_created: String,
+    date_updated: String,
+    account_sid: String,
+    to: String,
+    from: String,
+    status: String,
+    start_time: Option<String>,
+    end_time: Option<String>,
+    duration: Option<String>,
+    price: Option<String>,
+    price_unit: Option<String>,
+    direction: String,
+    answered_by: Option<String>,
+    api_version: String,
+    uri: String,
+}
+
+impl CallReply {
+    /// Deserialize the response from a `&str`
+    pub fn decode_str(response: &str) -> Result<CallReply, serde_json::error::Error> {
+        serde_json::from_str(response)
+    }
+
+    pub(crate) async fn decode_async(response: reqwest::Response) -> Result<CallReply, TWRSError> {
+        let text = response.text().await.map_err(TWRSError::HTTPRequestError)?;
+
+        CallReply::decode_str(&text).map_err(TWRSError::URLDecodeFailure)
+    }
+}
+
+impl TwilioClient {
+    /// Places an outbound call and returns the decoded Twilio reply
+    pub async fn call(&self, call: OutboundCall<'_>) -> Result<CallReply, TWRSError> {
+        let uri = format!("{}/{}/Calls.json", ENDPOINT, self.account_sid);
+        let body = call.encode()?;
+
+        let response = self
+            .client
+            .post(&uri)
+            .header("Content-Type", "application/x-www-form-urlencoded")
+            .basic_auth(&self.account_sid, Some(&self.auth_token))
+            .body(body)
+            .send()
+            .await
+            .map_err(TWRSError::HTTPRequestError)?;
+
+        CallReply::decode_async(response).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encoding() {
+        let mut call = OutboundCall::new();
+        call.r#From = "+11234567890";
+        call.To = "+10987654321";
+        call.Url = "https://example.com/twiml";
+
+        let encoded = call.encode().expect("Error converting to url encoded scheme");
+
+        assert_eq!(
+            encoded,
+            "From=%2B11234567890&To=%2B10987654321&Url=https%3A%2F%2Fexample.com%2Ftwiml"
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn test_decoding() {
+        let d = "{\"sid\": \"CAXXXX\", \"date_created\": \"Wed, 22 Jan 2020 15:23:30 +0000\", \"date_updated\": \"Wed, 22 Jan 2020 15:23:30 +0000\", \"account_sid\": \"ACXXXX\", \"to\": \"+11234567890\", \"from\": \"+10987654321\", \"status\": \"queued\", \"start_time\": null, \"end_time\": null, \"duration\": null, \"price\": null, \"price_unit\": \"USD\", \"direction\": \"outbound-api\", \"answered_by\": null, \"api_version\": \"2010-04-01\", \"uri\": \"/2010-04-01/Accounts/ACXXXX/Calls/CAXXXX.json\"}".to_string();
+
+        let call = CallReply::decode_str(&d).expect("Error decoding reply");
+
+        let expected = CallReply {
+            sid: "CAXXXX".to_string(),
+            date_created: "Wed, 22 Jan 2020 15:23:30 +0000".to_string(),
+            date_updated: "Wed, 22 Jan 2020 15:23:30 +0000".to_string(),
+            account_sid: "ACXXXX".to_string(),
+            to: "+11234567890".to_string(),
+            from: "+10987654321".to_string(),
+            status: "queued".to_string(),
+            start_time: None,
+            end_time: None,
+            duration: None,
+            price: None,
+            price_unit: Some("USD".to_string()),
+            direction: "outbound-api".to_string(),
+            answered_by: None,
+            api_version: "2010-04-01".to_string(),
+            uri: "/2010-04-01/Accounts/ACXXXX/Calls/CAXXXX.json".to_string(),
+        };
+
+        assert_eq!(call, expected);
+    }
+}
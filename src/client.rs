@@ -0,0 +1,151 @@
+//! An async, non-blocking Twilio SMS client built on `reqwest`'s async API.
+//!
+//! Unlike [`crate::blocking`], `TwilioClient` never blocks the calling thread, so it can be
+//! awaited directly from inside a tokio (or other async) runtime without spawning a blocking
+//! task.
+
+use std::cmp::min;
+
+use crate::{MessageStatus, PollConfig, TWRSError, TwilioReply, TwilioSend, ENDPOINT};
+
+/// An async Twilio client, holding the account credentials and a reusable `reqwest::Client`.
+///
+/// ```no_run
+/// # async fn run() -> Result<(), twrs_sms::TWRSError> {
+/// use twrs_sms::client::TwilioClient;
+/// use twrs_sms::TwilioSend;
+///
+/// let twilio = TwilioClient::new("ACXXXX".to_string(), "auth_token".to_string());
+///
+/// let mut msg = TwilioSend::new();
+/// msg.From = "+11234567890";
+/// msg.To = "+10987654321";
+/// msg.Body = Some("Hiya");
+///
+/// let reply = twilio.send_message(msg).await?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct TwilioClient {
+    pub(crate) account_sid: String,
+    pub(crate) auth_token: String,
+    pub(crate) client: reqwest::Client,
+}
+
+impl TwilioClient {
+    /// Creates a new `TwilioClient` from an account SID and auth token, using a fresh
+    /// `reqwest::Client`
+    pub fn new(account_sid: String, auth_token: String) -> TwilioClient {
+        TwilioClient {
+            account_sid,
+            auth_token,
+            client: reqwest::Client::new(),
+        }
+    }
+
+    /// Sends an SMS (or MMS) message and returns the decoded Twilio reply
+    pub async fn send_message(&self, msg: TwilioSend<'_>) -> Result<TwilioReply, TWRSError> {
+        let uri = format!("{}/{}/Messages.json", ENDPOINT, self.account_sid);
+        let body = msg.encode()?;
+
+        let response = self
+            .client
+            .post(&uri)
+            .header("Content-Type", "application/x-www-form-urlencoded")
+            .basic_auth(&self.account_sid, Some(&self.auth_token))
+            .body(body)
+            .send()
+            .await
+            .map_err(TWRSError::HTTPRequestError)?;
+
+        TwilioReply::decode_async(response).await
+    }
+
+    /// Checks the delivery status of a previously sent message by its `sid`
+    ///
+    /// Mirrors [`crate::blocking::is_delivered`]: it polls the message resource with
+    /// exponential backoff (per `config`) while the status is pending (see
+    /// `MessageStatus::is_pending`). Returns `TWRSError::NotDelivered` if the message reaches a
+    /// terminal failure status, or `TWRSError::Timeout` if `config.max_attempts` is exhausted
+    /// while still pending.
+    pub async fn is_delivered(&self, sid: &str, config: PollConfig) -> Result<MessageStatus, TWRSError> {
+        let uri = format!("{}/{}/Messages/{}.json", ENDPOINT, self.account_sid, sid);
+
+        let mut status = self.fetch_message_status(&uri).await?;
+        let mut delay = config.initial_delay;
+
+        for _ in 0..config.max_attempts {
+            if !status.is_pending() {
+                break;
+            }
+
+            tokio::time::sleep(delay).await;
+            delay = min(delay * 2, config.max_delay);
+
+            status = self.fetch_message_status(&uri).await?;
+        }
+
+        match status {
+            MessageStatus::Delivered | MessageStatus::Read => Ok(status),
+            _ if status.is_pending() => Err(TWRSError::Timeout(status)),
+            _ => Err(TWRSError::NotDelivered(status)),
+        }
+    }
+
+    async fn fetch_message_status(&self, uri: &str) -> Result<MessageStatus, TWRSError> {
+        let response = self
+            .client
+            .get(uri)
+            .basic_auth(&self.account_sid, Some(&self.auth_token))
+            .send()
+            .await
+            .map_err(TWRSError::HTTPRequestError)?;
+
+        TwilioReply::decode_async(response).await.map(|reply| reply.status)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[tokio::test]
+    #[ignore]
+    async fn test_full() {
+        // Be sure to have the follow environment variables set before running this ignored test
+        // export TW_TO="COUNTRYCODE_PHONENUMBER"
+        // export TW_FROM="COUNTRYCODE_PHONENUMBER"
+        // export TW_SID="ACCOUNT_SID"
+        // export TW_TOKEN="ACCOUNT_TOKEN"
+        use crate::client::TwilioClient;
+        use crate::{MessageStatus, PollConfig, TwilioSend};
+        use std::env::var;
+
+        // Getting your Twilio info to test sending an SMS
+        let tw_to = var("TW_TO").unwrap();
+        let tw_from = var("TW_FROM").unwrap();
+        let tw_sid = var("TW_SID").unwrap();
+        let tw_token = var("TW_TOKEN").unwrap();
+
+        let twilio = TwilioClient::new(tw_sid, tw_token);
+
+        // Create the request body and encode the message for the API
+        let mut msg = TwilioSend::new();
+        msg.To = &tw_to;
+        msg.From = &tw_from;
+        msg.Body = Some("Hiya");
+
+        // Send the message to the API endpoint
+        let reply = twilio
+            .send_message(msg)
+            .await
+            .expect("Error with HTTP request");
+
+        // Run the loop to make sure the message was delivered
+        let delivered = twilio
+            .is_delivered(&reply.sid, PollConfig::default())
+            .await
+            .expect("Error SMS not delivered");
+
+        // Checking the delivered state, and fail on an error
+        assert_eq!(delivered, MessageStatus::Delivered);
+    }
+}
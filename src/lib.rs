@@ -1,24 +1,45 @@
 use std::collections::HashMap;
 use std::error::Error;
 use std::fmt;
+use std::time::Duration;
 
 use reqwest;
 use serde::{Deserialize, Serialize};
 use serde_json as json;
 use serde_urlencoded as url_encode;
 
+#[cfg(feature = "blocking")]
+pub mod blocking;
+
+pub mod calls;
+pub mod client;
+pub mod messages;
+pub mod twiml;
+
+/// Base URL for the Twilio REST API, shared by every resource-specific module
+pub(crate) const ENDPOINT: &str = "https://api.twilio.com/2010-04-01/Accounts";
+
 /// Creating a custom error for mapping Errors to return result from the library handles
-/// The possible errors are `URLEncodeFailure`, `URLDecodeFailure`, `HTTPRequestError`, and `NotDelivered`
+/// The possible errors are `URLEncodeFailure`, `URLDecodeFailure`, `URLFormDecodeFailure`,
+/// `HTTPRequestError`, `Utf8DecodeFailure`, `NotDelivered`, and `Timeout`
 /// `URLDecodeFailure` maps to a `serde_json::error::Error`
 /// `URLEncodeFailure` maps to a `serde_urlencoded::ser::Error`
+/// `URLFormDecodeFailure` maps to a `serde_urlencoded::de::Error`
 /// `HTTPRequestError` maps to a `reqwest::error::Error`
+/// `Utf8DecodeFailure` maps to a `std::string::FromUtf8Error`, returned when a response body
+/// isn't valid UTF-8
 /// `NotDelivered` is a custom error that is sent when an SMS was not delivered
+/// `Timeout` is sent when `is_delivered` exhausts `PollConfig::max_attempts` while the message
+/// is still pending
 #[derive(Debug)]
 pub enum TWRSError {
     URLEncodeFailure(serde_urlencoded::ser::Error),
     URLDecodeFailure(serde_json::error::Error),
+    URLFormDecodeFailure(serde_urlencoded::de::Error),
     HTTPRequestError(reqwest::Error),
-    NotDelivered(String),
+    Utf8DecodeFailure(std::string::FromUtf8Error),
+    NotDelivered(MessageStatus),
+    Timeout(MessageStatus),
 }
 
 impl fmt::Display for TWRSError {
@@ -30,23 +51,83 @@ impl fmt::Display for TWRSError {
             TWRSError::URLDecodeFailure(e) => {
                 write!(f, "Error while serializing URL to encoded string: {}", e)
             }
+            TWRSError::URLFormDecodeFailure(e) => {
+                write!(f, "Error while decoding url encoded form data: {}", e)
+            }
             TWRSError::HTTPRequestError(e) => write!(f, "Error while sending HTTP POST: {}", e),
-            TWRSError::NotDelivered(e) => write!(f, "Error message not delivered: {}", e),
+            TWRSError::Utf8DecodeFailure(e) => {
+                write!(f, "Error decoding response body as UTF-8: {}", e)
+            }
+            TWRSError::NotDelivered(e) => write!(f, "Error message not delivered: {:?}", e),
+            TWRSError::Timeout(e) => write!(
+                f,
+                "Timed out polling for delivery, last status was: {:?}",
+                e
+            ),
         }
     }
 }
 
 impl Error for TWRSError {}
 
+/// Configuration for the exponential-backoff polling done by `is_delivered` in both
+/// [`crate::blocking`] and [`crate::client`]
+///
+/// The delay between attempts starts at `initial_delay`, doubles after each attempt, and is
+/// capped at `max_delay`. Polling gives up with `TWRSError::Timeout` after `max_attempts`.
+#[derive(Debug, Clone, Copy)]
+pub struct PollConfig {
+    pub initial_delay: Duration,
+    pub max_delay: Duration,
+    pub max_attempts: u32,
+}
+
+impl PollConfig {
+    pub fn new(initial_delay: Duration, max_delay: Duration, max_attempts: u32) -> PollConfig {
+        PollConfig {
+            initial_delay,
+            max_delay,
+            max_attempts,
+        }
+    }
+}
+
+impl Default for PollConfig {
+    /// 500ms initial delay, doubling up to a 30s cap, for at most 10 attempts
+    fn default() -> PollConfig {
+        PollConfig {
+            initial_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+            max_attempts: 10,
+        }
+    }
+}
+
 /// Custom struct to serialize the HTTP POST data into a url encoded objecting using serde_urlencoded
 /// For a description of these fields see the [Official Twilio Developer Documentation](https://www.twilio.com/docs/sms)
-/// All fields must exist so none of them is given the Serde ignore on None tag
+/// `Body` is optional since a media-only (MMS) message is valid without any text
 #[allow(non_snake_case)]
 #[derive(Serialize, Deserialize)]
 pub struct TwilioSend<'s> {
-    pub Body: &'s str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub Body: Option<&'s str>,
     pub r#From: &'s str,
     pub To: &'s str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub MessagingServiceSid: Option<&'s str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub SendAt: Option<&'s str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ScheduleType: Option<&'s str>,
+    /// A URL Twilio POSTs a `StatusCallback` (see [`StatusCallback`]) to every time the
+    /// message's status changes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub StatusCallback: Option<&'s str>,
+    /// Media to attach to the message, one picture/video per url. Twilio accepts this as
+    /// multiple repeated `MediaUrl` form fields, which `serde_urlencoded` can't produce from a
+    /// `Vec`, so these are appended onto the encoded body by hand in `encode`
+    #[serde(skip)]
+    pub MediaUrl: Vec<&'s str>,
 }
 
 /// Creates a new instance of the body that is posted to the Twilio API
@@ -55,13 +136,87 @@ impl<'s> TwilioSend<'s> {
         TwilioSend {
             r#From: "",
             To: "",
-            Body: "",
+            Body: None,
+            MessagingServiceSid: None,
+            SendAt: None,
+            ScheduleType: None,
+            StatusCallback: None,
+            MediaUrl: Vec::new(),
         }
     }
 
+    /// Adds a media URL to attach to the message, e.g. for picture messages. Can be called
+    /// more than once; Twilio accepts up to 10 `MediaUrl` values per message
+    pub fn media(mut self, url: &'s str) -> TwilioSend<'s> {
+        self.MediaUrl.push(url);
+        self
+    }
+
+    /// Schedules the message for delivery at `send_at` (an RFC 3339 timestamp) instead of
+    /// immediately. Twilio only supports scheduling through a Messaging Service, and requires
+    /// `ScheduleType=fixed` to be sent alongside `SendAt`, both of which this sets
+    pub fn schedule(mut self, messaging_service_sid: &'s str, send_at: &'s str) -> TwilioSend<'s> {
+        self.MessagingServiceSid = Some(messaging_service_sid);
+        self.SendAt = Some(send_at);
+        self.ScheduleType = Some("fixed");
+        self
+    }
+
+    /// Sets a URL Twilio will POST delivery status updates to as the message moves through its
+    /// lifecycle, so callers don't have to rely solely on polling `is_delivered`
+    pub fn status_callback(mut self, url: &'s str) -> TwilioSend<'s> {
+        self.StatusCallback = Some(url);
+        self
+    }
+
     /// This function converts from the struct to a string of url encoded formatting
     pub fn encode(self) -> Result<String, TWRSError> {
-        url_encode::to_string(&self).map_err(TWRSError::URLEncodeFailure)
+        let mut encoded = url_encode::to_string(&self).map_err(TWRSError::URLEncodeFailure)?;
+
+        for url in &self.MediaUrl {
+            let pair =
+                url_encode::to_string([("MediaUrl", url)]).map_err(TWRSError::URLEncodeFailure)?;
+            encoded.push('&');
+            encoded.push_str(&pair);
+        }
+
+        Ok(encoded)
+    }
+}
+
+/// The lifecycle status of a Twilio message resource
+///
+/// See the [Twilio message resource docs](https://www.twilio.com/docs/sms/api/message-resource#message-status-values)
+/// for the full description of each value.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Eq, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum MessageStatus {
+    Accepted,
+    Scheduled,
+    Queued,
+    Sending,
+    Sent,
+    Receiving,
+    Received,
+    Delivered,
+    Undelivered,
+    Failed,
+    Read,
+    Canceled,
+}
+
+impl MessageStatus {
+    /// `true` while Twilio is still working the message towards a final state, i.e. it's
+    /// worth polling again
+    pub fn is_pending(&self) -> bool {
+        matches!(
+            self,
+            MessageStatus::Accepted
+                | MessageStatus::Scheduled
+                | MessageStatus::Queued
+                | MessageStatus::Sending
+                | MessageStatus::Sent
+        )
     }
 }
 
@@ -78,7 +233,7 @@ pub struct TwilioReply {
     from: String,
     messaging_service_sid: Option<String>,
     body: String,
-    status: String,
+    status: MessageStatus,
     num_segments: String,
     num_media: String,
     direction: String,
@@ -92,67 +247,37 @@ pub struct TwilioReply {
 }
 
 impl TwilioReply {
-    /// Deserialize the response from the Twilio API directly from the `reqwest::blocking::Response`
-    /// struct
-    pub fn decode(response: &mut reqwest::blocking::Response) -> Result<TwilioReply, TWRSError> {
-        let mut buf: Vec<u8> = Vec::new();
-        response
-            .copy_to(&mut buf)
-            .expect("Error copying bytes to String buffer");
-        let str_t = String::from_utf8(buf).expect("Error decoding as UTF-8 from Response");
-
-        json::from_str(&str_t).map_err(TWRSError::URLDecodeFailure)
-    }
-
     /// Deserialize the response from a `&str`
     pub fn decode_str(response: &str) -> Result<TwilioReply, serde_json::error::Error> {
         json::from_str(&response)
     }
+
+    /// Deserialize the response from the Twilio API directly from an async `reqwest::Response`
+    pub(crate) async fn decode_async(response: reqwest::Response) -> Result<TwilioReply, TWRSError> {
+        let text = response.text().await.map_err(TWRSError::HTTPRequestError)?;
+
+        json::from_str(&text).map_err(TWRSError::URLDecodeFailure)
+    }
 }
 
-/// Main function of the library which sends the request and returns the response
-/// response. Will error out on a `TWRSError::HTTPRequestError` if the send results in a failure
-pub fn send_message(
-    account_sid: &str,
-    auth_token: &str,
-    body: String,
-) -> Result<reqwest::blocking::Response, TWRSError> {
-    let endpoint = "https://api.twilio.com/2010-04-01/Accounts".to_string();
-    let uri = format!("{}/{}/Messages.json", endpoint, account_sid);
-
-    reqwest::blocking::Client::new()
-        .post(&uri)
-        .header("Content-Type", "application/x-www-form-urlencoded")
-        .basic_auth(account_sid, Some(auth_token))
-        .body(body)
-        .send()
-        .map_err(TWRSError::HTTPRequestError)
+/// The payload Twilio POSTs as `application/x-www-form-urlencoded` to a message's
+/// `StatusCallback` URL (set via [`TwilioSend::status_callback`]) every time its status
+/// changes, letting a webhook endpoint track delivery without polling `is_delivered`
+#[allow(non_snake_case)]
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq)]
+pub struct StatusCallback {
+    pub MessageSid: String,
+    pub MessageStatus: MessageStatus,
+    pub To: String,
+    pub From: String,
+    pub ApiVersion: String,
+    pub ErrorCode: Option<String>,
 }
-/// This will check if the status is set to delivered within the Twilio API
-/// Within this function is a while loop that breaks on the API returning anything other than
-/// `delivered`, if the response is not delivered this will return `TWRSError::NotDelivered`
-pub fn is_delivered<'r>(
-    response: &mut reqwest::blocking::Response,
-    account_sid: &str,
-    auth_token: &str,
-) -> Result<&'r str, TWRSError> {
-    let resp_body = TwilioReply::decode(response).expect("Error decoding response");
-    let mut resp_status = resp_body.status;
-    let url = format!("https://api.twilio.com/{}", resp_body.uri);
-
-    while resp_status == "queued" || resp_status == "sent" {
-        let mut sub_r = reqwest::blocking::Client::new()
-            .get(&url)
-            .basic_auth(account_sid, Some(auth_token))
-            .send()
-            .expect("Error sending response inspector get request");
-        let sub_res = TwilioReply::decode(&mut sub_r).expect("Error decoding response from server");
-        resp_status = sub_res.status;
-    }
 
-    match resp_status.as_ref() {
-        "delivered" => Ok("delivered"),
-        _ => Err(TWRSError::NotDelivered(resp_status)),
+impl StatusCallback {
+    /// Decodes the payload from a webhook request's url-encoded body
+    pub fn decode(body: &str) -> Result<StatusCallback, TWRSError> {
+        url_encode::from_str(body).map_err(TWRSError::URLFormDecodeFailure)
     }
 }
 
@@ -165,7 +290,7 @@ mod tests {
         let mut tw = twrs_sms::TwilioSend::new();
         tw.From = "+11234567890";
         tw.To = "+10987654321";
-        tw.Body = "Hello, world!";
+        tw.Body = Some("Hello, world!");
 
         let tw_e = tw.encode().expect("Error converting to url encoded scheme");
 
@@ -175,6 +300,64 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_encoding_media_only() {
+        use crate as twrs_sms;
+
+        let tw = twrs_sms::TwilioSend::new()
+            .media("http://example.com/img.jpg")
+            .media("http://example.com/img2.jpg");
+
+        let tw_e = tw.encode().expect("Error converting to url encoded scheme");
+
+        assert_eq!(
+            tw_e,
+            "From=&To=&MediaUrl=http%3A%2F%2Fexample.com%2Fimg.jpg&MediaUrl=http%3A%2F%2Fexample.com%2Fimg2.jpg".to_string()
+        );
+    }
+
+    #[test]
+    fn test_encoding_scheduled_with_status_callback() {
+        use crate as twrs_sms;
+
+        let mut tw = twrs_sms::TwilioSend::new();
+        tw.From = "+11234567890";
+        tw.To = "+10987654321";
+        tw.Body = Some("Hiya");
+        let tw = tw
+            .schedule("MGXXXX", "2026-08-01T09:00:00Z")
+            .status_callback("https://example.com/status");
+
+        let tw_e = tw.encode().expect("Error converting to url encoded scheme");
+
+        assert_eq!(
+            tw_e,
+            "Body=Hiya&From=%2B11234567890&To=%2B10987654321&MessagingServiceSid=MGXXXX&SendAt=2026-08-01T09%3A00%3A00Z&ScheduleType=fixed&StatusCallback=https%3A%2F%2Fexample.com%2Fstatus".to_string()
+        );
+    }
+
+    #[test]
+    fn test_status_callback_decode() {
+        use crate as twrs_sms;
+
+        let body = "MessageSid=SMXXXX&MessageStatus=delivered&To=%2B11234567890&From=%2B10987654321&ApiVersion=2010-04-01";
+
+        let callback =
+            twrs_sms::StatusCallback::decode(body).expect("Error decoding status callback");
+
+        assert_eq!(
+            callback,
+            twrs_sms::StatusCallback {
+                MessageSid: "SMXXXX".to_string(),
+                MessageStatus: twrs_sms::MessageStatus::Delivered,
+                To: "+11234567890".to_string(),
+                From: "+10987654321".to_string(),
+                ApiVersion: "2010-04-01".to_string(),
+                ErrorCode: None,
+            }
+        );
+    }
+
     #[test]
     fn test_decoding() {
         use crate as twrs_sms;
@@ -193,7 +376,7 @@ mod tests {
             from: "+10987654321".to_string(),
             messaging_service_sid: None,
             body: "Sent from your Twilio trial account - Hiya".to_string(),
-            status: "queued".to_string(),
+            status: twrs_sms::MessageStatus::Queued,
             num_segments: "1".to_string(),
             num_media: "0".to_string(),
             direction: "outbound-api".to_string(),
@@ -216,46 +399,4 @@ mod tests {
 
         assert_eq!(t_r, expected);
     }
-
-    #[test]
-    #[ignore]
-    fn test_full() {
-        // Be sure to have the follow environment variables set before running this ignored test
-        // export TW_TO="COUNTRYCODE_PHONENUMBER"
-        // export TW_FROM="COUNTRYCODE_PHONENUMBER"
-        // export TW_SID="ACCOUNT_SID"
-        // export TW_TOKEN="ACCOUNT_TOKEN"
-        use crate as twrs_sms;
-        use std::env::var;
-
-        use reqwest::StatusCode;
-
-        // Getting your Twilio info to test sending an SMS
-        let tw_to = var("TW_TO").unwrap();
-        let tw_from = var("TW_FROM").unwrap();
-        let tw_sid = var("TW_SID").unwrap();
-        let tw_token = var("TW_TOKEN").unwrap();
-
-        // Create the request body and encode the message for the API
-        let t: twrs_sms::TwilioSend = twrs_sms::TwilioSend {
-            To: &tw_to,
-            From: &tw_from,
-            Body: "Hiya",
-        };
-        let t_s = t.encode().expect("Error converting to url encoded string");
-
-        // Send the message to the API endpoint
-        let mut response =
-            twrs_sms::send_message(&tw_sid, &tw_token, t_s).expect("Error with HTTP request");
-
-        // Server responds with 201 (Created) on the initial response
-        assert_eq!(StatusCode::from_u16(201).unwrap(), response.status());
-
-        // Run the loop to make sure the message was delivered
-        let delivered = twrs_sms::is_delivered(&mut response, &tw_sid, &tw_token)
-            .expect("Error SMS not delivered");
-
-        // Checking the delivered state, and fail on an error
-        assert_eq!(delivered, "delivered");
-    }
 }
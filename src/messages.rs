@@ -0,0 +1,227 @@
+//! Message-management endpoints beyond sending and polling: fetching a single message,
+//! listing messages with date filters, redacting a message's body for compliance, and
+//! deleting a message outright.
+
+use serde::Deserialize;
+use serde_json as json;
+
+use crate::client::TwilioClient;
+use crate::{TWRSError, TwilioReply, ENDPOINT};
+
+/// A single page of the `/Messages.json` list endpoint
+#[derive(Deserialize)]
+struct MessagePage {
+    messages: Vec<TwilioReply>,
+    next_page_uri: Option<String>,
+}
+
+/// A builder for the `/Messages.json` list endpoint, returned by [`TwilioClient::list`]
+///
+/// Twilio's date filters are mutually exclusive: use [`MessageQuery::between`] for a range, or
+/// [`MessageQuery::on`] for a single day.
+pub struct MessageQuery<'c> {
+    client: &'c TwilioClient,
+    date_sent: Option<String>,
+    date_sent_after: Option<String>,
+    date_sent_before: Option<String>,
+}
+
+impl<'c> MessageQuery<'c> {
+    /// Restricts the list to messages sent between `start` and `end` (inclusive, `YYYY-MM-DD`).
+    /// Clears any date previously set via [`MessageQuery::on`], since the two filters are
+    /// mutually exclusive
+    pub fn between(mut self, start: &str, end: &str) -> MessageQuery<'c> {
+        self.date_sent = None;
+        self.date_sent_after = Some(start.to_string());
+        self.date_sent_before = Some(end.to_string());
+        self
+    }
+
+    /// Restricts the list to messages sent on a single day (`YYYY-MM-DD`). Clears any range
+    /// previously set via [`MessageQuery::between`], since the two filters are mutually
+    /// exclusive
+    pub fn on(mut self, date: &str) -> MessageQuery<'c> {
+        self.date_sent = Some(date.to_string());
+        self.date_sent_after = None;
+        self.date_sent_before = None;
+        self
+    }
+
+    fn query_pairs(&self) -> Vec<(&str, &str)> {
+        let mut pairs = Vec::new();
+
+        if let Some(date) = &self.date_sent {
+            pairs.push(("DateSent", date.as_str()));
+        }
+        if let Some(after) = &self.date_sent_after {
+            pairs.push(("DateSent>=", after.as_str()));
+        }
+        if let Some(before) = &self.date_sent_before {
+            pairs.push(("DateSent<=", before.as_str()));
+        }
+
+        pairs
+    }
+
+    /// Runs the query, following `next_page_uri` until Twilio reports no further pages
+    pub async fn execute(self) -> Result<Vec<TwilioReply>, TWRSError> {
+        let query = serde_urlencoded::to_string(self.query_pairs())
+            .map_err(TWRSError::URLEncodeFailure)?;
+
+        let first_uri = format!("{}/{}/Messages.json", ENDPOINT, self.client.account_sid);
+        let mut uri = if query.is_empty() {
+            first_uri
+        } else {
+            format!("{}?{}", first_uri, query)
+        };
+
+        let mut messages = Vec::new();
+
+        loop {
+            let response = self
+                .client
+                .client
+                .get(&uri)
+                .basic_auth(&self.client.account_sid, Some(&self.client.auth_token))
+                .send()
+                .await
+                .map_err(TWRSError::HTTPRequestError)?;
+
+            let text = response.text().await.map_err(TWRSError::HTTPRequestError)?;
+            let page: MessagePage = json::from_str(&text).map_err(TWRSError::URLDecodeFailure)?;
+
+            messages.extend(page.messages);
+
+            match page.next_page_uri {
+                Some(next_page_uri) => uri = format!("https://api.twilio.com{}", next_page_uri),
+                None => break,
+            }
+        }
+
+        Ok(messages)
+    }
+}
+
+impl TwilioClient {
+    /// Fetches a single message resource by its `sid`
+    pub async fn fetch(&self, sid: &str) -> Result<TwilioReply, TWRSError> {
+        let uri = format!("{}/{}/Messages/{}.json", ENDPOINT, self.account_sid, sid);
+
+        let response = self
+            .client
+            .get(&uri)
+            .basic_auth(&self.account_sid, Some(&self.auth_token))
+            .send()
+            .await
+            .map_err(TWRSError::HTTPRequestError)?;
+
+        TwilioReply::decode_async(response).await
+    }
+
+    /// Starts a query over the account's messages, optionally narrowed with
+    /// [`MessageQuery::between`] or [`MessageQuery::on`]
+    pub fn list(&self) -> MessageQuery<'_> {
+        MessageQuery {
+            client: self,
+            date_sent: None,
+            date_sent_after: None,
+            date_sent_before: None,
+        }
+    }
+
+    /// Redacts a message's body for compliance by overwriting it with an empty string. Twilio
+    /// keeps the rest of the message resource (sid, status, etc.) intact
+    pub async fn redact(&self, sid: &str) -> Result<TwilioReply, TWRSError> {
+        let uri = format!("{}/{}/Messages/{}.json", ENDPOINT, self.account_sid, sid);
+
+        let response = self
+            .client
+            .post(&uri)
+            .header("Content-Type", "application/x-www-form-urlencoded")
+            .basic_auth(&self.account_sid, Some(&self.auth_token))
+            .body("Body=")
+            .send()
+            .await
+            .map_err(TWRSError::HTTPRequestError)?;
+
+        TwilioReply::decode_async(response).await
+    }
+
+    /// Permanently deletes a message resource
+    pub async fn delete(&self, sid: &str) -> Result<(), TWRSError> {
+        let uri = format!("{}/{}/Messages/{}.json", ENDPOINT, self.account_sid, sid);
+
+        self.client
+            .delete(&uri)
+            .basic_auth(&self.account_sid, Some(&self.auth_token))
+            .send()
+            .await
+            .map_err(TWRSError::HTTPRequestError)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::client::TwilioClient;
+
+    fn client() -> TwilioClient {
+        TwilioClient::new("ACXXXX".to_string(), "auth_token".to_string())
+    }
+
+    #[test]
+    fn test_query_pairs_between() {
+        let client = client();
+        let query = client.list().between("2026-01-01", "2026-01-31");
+
+        assert_eq!(
+            query.query_pairs(),
+            vec![
+                ("DateSent>=", "2026-01-01"),
+                ("DateSent<=", "2026-01-31"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_query_pairs_on() {
+        let client = client();
+        let query = client.list().on("2026-01-15");
+
+        assert_eq!(query.query_pairs(), vec![("DateSent", "2026-01-15")]);
+    }
+
+    #[test]
+    fn test_query_pairs_between_then_on_is_exclusive() {
+        let client = client();
+        let query = client
+            .list()
+            .between("2026-01-01", "2026-01-31")
+            .on("2026-01-15");
+
+        assert_eq!(query.query_pairs(), vec![("DateSent", "2026-01-15")]);
+    }
+
+    #[test]
+    fn test_query_pairs_on_then_between_is_exclusive() {
+        let client = client();
+        let query = client
+            .list()
+            .on("2026-01-15")
+            .between("2026-01-01", "2026-01-31");
+
+        assert_eq!(
+            query.query_pairs(),
+            vec![("DateSent>=", "2026-01-01"), ("DateSent<=", "2026-01-31")]
+        );
+    }
+
+    #[test]
+    fn test_query_pairs_empty() {
+        let client = client();
+        let query = client.list();
+
+        assert!(query.query_pairs().is_empty());
+    }
+}
@@ -0,0 +1,96 @@
+//! A small builder for TwiML, the XML dialect Twilio fetches from a call's `url` and executes
+//! to control the call (see [`crate::calls::OutboundCall`]).
+//!
+//! ```
+//! use twrs_sms::twiml::Twiml;
+//!
+//! let doc = Twiml::new().say("Hello there").dial("+11234567890").build();
+//!
+//! assert_eq!(
+//!     doc,
+//!     "<?xml version=\"1.0\" encoding=\"UTF-8\"?><Response><Say>Hello there</Say><Dial>+11234567890</Dial></Response>"
+//! );
+//! ```
+
+/// Builds a `<Response>` TwiML document verb by verb
+#[derive(Default)]
+pub struct Twiml {
+    verbs: Vec<String>,
+}
+
+impl Twiml {
+    pub fn new() -> Twiml {
+        Twiml::default()
+    }
+
+    /// Adds a `<Say>` verb, which has Twilio read `text` aloud using text-to-speech
+    pub fn say(mut self, text: &str) -> Twiml {
+        self.verbs.push(format!("<Say>{}</Say>", escape(text)));
+        self
+    }
+
+    /// Adds a `<Play>` verb, which has Twilio play the audio file at `url`
+    pub fn play(mut self, url: &str) -> Twiml {
+        self.verbs.push(format!("<Play>{}</Play>", escape(url)));
+        self
+    }
+
+    /// Adds a `<Dial>` verb, which connects the call to `number`
+    pub fn dial(mut self, number: &str) -> Twiml {
+        self.verbs.push(format!("<Dial>{}</Dial>", escape(number)));
+        self
+    }
+
+    /// Adds a `<Hangup/>` verb, which ends the call
+    pub fn hangup(mut self) -> Twiml {
+        self.verbs.push("<Hangup/>".to_string());
+        self
+    }
+
+    /// Renders the accumulated verbs into a complete TwiML document
+    pub fn build(self) -> String {
+        format!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?><Response>{}</Response>",
+            self.verbs.join("")
+        )
+    }
+}
+
+/// Escapes the handful of characters that are significant in XML text content
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_builder() {
+        use crate::twiml::Twiml;
+
+        let doc = Twiml::new()
+            .say("Hello there")
+            .play("https://example.com/hold-music.mp3")
+            .dial("+11234567890")
+            .hangup()
+            .build();
+
+        assert_eq!(
+            doc,
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?><Response><Say>Hello there</Say><Play>https://example.com/hold-music.mp3</Play><Dial>+11234567890</Dial><Hangup/></Response>"
+        );
+    }
+
+    #[test]
+    fn test_escaping() {
+        use crate::twiml::Twiml;
+
+        let doc = Twiml::new().say("Tom & Jerry <3").build();
+
+        assert_eq!(
+            doc,
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?><Response><Say>Tom &amp; Jerry &lt;3</Say></Response>"
+        );
+    }
+}